@@ -37,11 +37,19 @@ mod geometry_sink;
 mod matrix;
 
 mod nullable_ref;
+mod stroke;
+mod svg_path;
+mod loop_blinn;
 
 #[cfg(feature = "c_bindings")]
 pub mod c_bindings;
 
-use std::{rc::Rc, cell::RefCell};
+pub use stroke::{LineCap, LineJoin, StrokeStyle};
+pub use svg_path::ParseError;
+pub use loop_blinn::{CurveRenderingMode, LoopBlinnVertex};
+use stroke::PathCommand;
+
+use std::{rc::Rc, cell::RefCell, collections::HashMap};
 
 use aarasterizer::CheckValidRange28_4;
 use hwrasterizer::CHwRasterizer;
@@ -51,13 +59,20 @@ use real::CFloatFPU;
 use types::{CoordinateSpace, CD3DDeviceLevel1, IShapeData, MilFillMode, PathPointTypeStart, MilPoint2F, PathPointTypeLine, MilVertexFormat, MilVertexFormatAttribute, DynArray, BYTE, PathPointTypeBezier, PathPointTypeCloseSubpath, CMILSurfaceRect, POINT};
 
 #[repr(C)]
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, Copy)]
 pub struct OutputVertex {
     pub x: f32,
     pub y: f32,
     pub coverage: f32
 }
 
+impl PartialEq for OutputVertex {
+    fn eq(&self, other: &Self) -> bool {
+        self.x.to_bits() == other.x.to_bits() && self.y.to_bits() == other.y.to_bits() && self.coverage.to_bits() == other.coverage.to_bits()
+    }
+}
+impl Eq for OutputVertex {}
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub enum FillMode {
@@ -95,8 +110,21 @@ pub struct PathBuilder {
     outside_bounds: Option<CMILSurfaceRect>,
     need_inside: bool,
     valid_range: bool,
+    // Mirrors the path as a sequence of float-space commands, used by
+    // rasterize_stroke_to_tri_strip to build the stroke outline. This is kept
+    // separate from `points`/`types` because those are already rounded to
+    // 28.4 fixed point for the fill rasterizer.
+    commands: Vec<PathCommand>,
+    // world-to-device affine transform, in the `matrix(a, b, c, d, e, f)` convention:
+    // x' = a*x + c*y + e, y' = b*x + d*y + f. Applied to every point before the
+    // 28.4 range check and fixed-point rounding, so that range validation and
+    // curve flattening both see device-space coordinates.
+    transform: (f32, f32, f32, f32, f32, f32),
+    curve_rendering_mode: CurveRenderingMode,
 }
 
+const IDENTITY_TRANSFORM: (f32, f32, f32, f32, f32, f32) = (1.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+
 impl PathBuilder {
     pub fn new() -> Self {
         Self {
@@ -108,9 +136,34 @@ impl PathBuilder {
             outside_bounds: None,
             need_inside: true,
             valid_range: true,
+            commands: Vec::new(),
+            transform: IDENTITY_TRANSFORM,
+            curve_rendering_mode: CurveRenderingMode::default(),
         }
     }
+    /// Records the curve rendering mode (flattened analytic-AA fill or
+    /// Loop-Blinn). The mode is only stored: no rasterize method reads it, and
+    /// the kind of output is set by calling either `rasterize_to_tri_strip` or
+    /// `rasterize_to_loop_blinn_mesh`.
+    pub fn set_curve_rendering_mode(&mut self, mode: CurveRenderingMode) {
+        self.curve_rendering_mode = mode;
+    }
+    /// Sets the affine transform applied to the coordinates of points added
+    /// after this call (points already added keep the transform that was in
+    /// effect when they were added), using the `matrix(a, b, c, d, e, f)` convention:
+    /// `x' = a*x + c*y + e`, `y' = b*x + d*y + f`. This lets callers rasterize
+    /// the same path at different scales/rotations (e.g. zoom levels) without
+    /// pre-multiplying points themselves.
+    pub fn set_transform(&mut self, a: f32, b: f32, c: f32, d: f32, e: f32, f: f32) {
+        self.transform = (a, b, c, d, e, f);
+    }
+    // Applies `self.transform` using the `matrix(a, b, c, d, e, f)` convention.
+    fn transform_point(&self, x: f32, y: f32) -> (f32, f32) {
+        let (a, b, c, d, e, f) = self.transform;
+        (a * x + c * y + e, b * x + d * y + f)
+    }
     fn add_point(&mut self, x: f32, y: f32) {
+        let (x, y) = self.transform_point(x, y);
         // Transform from pixel corner at 0.0 to pixel center at 0.0. Scale into 28.4 range.
         // Validate that the point before rounding is within expected bounds for the rasterizer.
         let (x, y) = ((x - 0.5) * 16.0, (y - 0.5) * 16.0);
@@ -126,9 +179,13 @@ impl PathBuilder {
                 self.types.push(PathPointTypeStart);
                 self.add_point(initial_point.X, initial_point.Y);
                 self.in_shape = true;
+                let (tx, ty) = self.transform_point(initial_point.X, initial_point.Y);
+                self.commands.push(PathCommand::MoveTo(tx, ty));
             }
             self.types.push(PathPointTypeLine);
             self.add_point(x, y);
+            let (tx, ty) = self.transform_point(x, y);
+            self.commands.push(PathCommand::LineTo(tx, ty));
         } else {
             self.initial_point = Some(MilPoint2F{X: x, Y: y})
         }
@@ -147,11 +204,17 @@ impl PathBuilder {
             self.add_point(initial_point.X, initial_point.Y);
             self.initial_point = Some(initial_point);
             self.in_shape = true;
+            let (tx, ty) = self.transform_point(initial_point.X, initial_point.Y);
+            self.commands.push(PathCommand::MoveTo(tx, ty));
         }
         self.types.push(PathPointTypeBezier);
         self.add_point(c1x, c1y);
         self.add_point(c2x, c2y);
         self.add_point(x, y);
+        let (tc1x, tc1y) = self.transform_point(c1x, c1y);
+        let (tc2x, tc2y) = self.transform_point(c2x, c2y);
+        let (tx2, ty2) = self.transform_point(x, y);
+        self.commands.push(PathCommand::CurveTo(tc1x, tc1y, tc2x, tc2y, tx2, ty2));
     }
     pub fn quad_to(&mut self, cx: f32, cy: f32, x: f32, y: f32) {
         // For now we just implement quad_to on top of curve_to.
@@ -174,6 +237,9 @@ impl PathBuilder {
         if let Some(last) = self.types.last_mut() {
             *last |= PathPointTypeCloseSubpath;
         }
+        if self.in_shape {
+            self.commands.push(PathCommand::Close);
+        }
         self.in_shape = false;
         self.initial_point = None;
     }
@@ -189,13 +255,11 @@ impl PathBuilder {
     /// - `IN(dest, geometry)` can be done with `outside_bounds` and `need_inside = false`
     /// - `IN(dest, geometry, alpha)` can be done with `outside_bounds` and `need_inside = true`
     ///
-    /// Note: trapezoidal areas won't be clipped to outside_bounds
     pub fn set_outside_bounds(&mut self, outside_bounds: Option<(i32, i32, i32, i32)>, need_inside: bool) {
         self.outside_bounds = outside_bounds.map(|r| CMILSurfaceRect { left: r.0, top: r.1, right: r.2, bottom: r.3 });
         self.need_inside = need_inside;
     }
 
-    /// Note: trapezoidal areas won't necessarily be clipped to the clip rect
     pub fn rasterize_to_tri_strip(&self, clip_x: i32, clip_y: i32, clip_width: i32, clip_height: i32) -> Box<[OutputVertex]> {
         if !self.valid_range {
             // If any of the points are outside of valid 28.4 range, then just return an empty triangle list.
@@ -213,6 +277,57 @@ impl PathBuilder {
         rasterize_to_tri_strip(self.fill_mode, &self.types, &self.points, x, y, width, height, self.need_inside, need_outside)
     }
 
+    /// Rasterizes the stroke (outline) of the path built so far instead of its fill.
+    ///
+    /// The path is first converted to a fill outline: segments are offset by
+    /// `style.width / 2` to either side, joins are inserted between consecutive
+    /// segments, and the ends of open subpaths are capped according to `style.cap`.
+    /// The resulting outline is then rasterized with the same antialiased fill
+    /// machinery as `rasterize_to_tri_strip`.
+    pub fn rasterize_stroke_to_tri_strip(&self, style: &StrokeStyle, clip_x: i32, clip_y: i32, clip_width: i32, clip_height: i32) -> Box<[OutputVertex]> {
+        if !self.valid_range {
+            return Box::new([]);
+        }
+        stroke::rasterize_stroke_to_tri_strip(&self.commands, style, clip_x, clip_y, clip_width, clip_height)
+    }
+
+    /// Like `rasterize_to_tri_strip`, but returns a deduplicated vertex buffer
+    /// together with a triangle index buffer instead of a triangle strip. This
+    /// is a more compact representation for dense tessellations, since shared
+    /// vertices (e.g. the antialiasing fringe along adjoining triangles) are
+    /// only stored once.
+    pub fn rasterize_to_indexed_mesh(&self, clip_x: i32, clip_y: i32, clip_width: i32, clip_height: i32) -> (Box<[OutputVertex]>, Box<[u32]>) {
+        let strip = self.rasterize_to_tri_strip(clip_x, clip_y, clip_width, clip_height);
+        tri_strip_to_indexed_mesh(&strip)
+    }
+
+    /// Rasterizes the path using the Loop-Blinn curve rendering mode: curve spans
+    /// are emitted as a small number of control triangles carrying per-vertex
+    /// `klm` implicit-curve coordinates instead of being flattened into many line
+    /// segments, which a fragment shader resolves at native resolution. This
+    /// method does not depend on `set_curve_rendering_mode`; it produces this
+    /// output whatever mode is set.
+    ///
+    /// Each subpath is fanned and filled independently with no cross-subpath
+    /// winding/even-odd accounting, unlike `rasterize_to_tri_strip`: a
+    /// multi-subpath path (e.g. a glyph with a counter, built from an outer and
+    /// an inner subpath) will render as overlapping solid fills rather than a
+    /// shape with a hole. Don't use this mode for paths that rely on a second
+    /// subpath to cut a hole.
+    pub fn rasterize_to_loop_blinn_mesh(&self) -> Box<[LoopBlinnVertex]> {
+        if !self.valid_range {
+            return Box::new([]);
+        }
+        loop_blinn::rasterize_to_loop_blinn_mesh(&self.commands)
+    }
+
+    /// Parses an SVG `d=` path-data string (the `M/m L/l H/h V/v C/c S/s Q/q T/t A/a Z`
+    /// command set) and builds the equivalent `PathBuilder`, so callers can feed
+    /// web/SVG geometry straight into the rasterizer.
+    pub fn from_svg_path(d: &str) -> Result<Self, ParseError> {
+        svg_path::parse_svg_path(d)
+    }
+
     pub fn get_path(&mut self) -> Option<OutputPath> {
         if self.valid_range && !self.points.is_empty() && !self.types.is_empty() {
             Some(OutputPath {
@@ -254,6 +369,9 @@ pub fn rasterize_to_tri_strip(
     /*
     device.m_rcViewport = device.clipRect;
     */
+    // `points` is already in device space here: `PathBuilder::set_transform` applies
+    // the caller's world-to-device matrix per-point (before the 28.4 range check and
+    // fixed-point rounding) rather than passing it down, so this stays the identity.
     let worldToDevice: CMatrix<CoordinateSpace::Shape, CoordinateSpace::Device> = CMatrix::Identity();
 
     struct PathShape {
@@ -306,7 +424,173 @@ pub fn rasterize_to_tri_strip(
 
     rasterizer.SendGeometry(vertexBuilder.clone(), points, types);
     vertexBuilder.borrow_mut().FlushTryGetVertexBuffer(None);
-    device.output.replace(Vec::new()).into_boxed_slice()
+    let strip = device.output.replace(Vec::new());
+    clip_tri_strip_to_rect(&strip, clip_x, clip_y, clip_width, clip_height)
+}
+
+// Clips every triangle of a triangle-strip vertex buffer to `[x, x+width] x [y,
+// y+height]` using Sutherland-Hodgman polygon clipping, interpolating `coverage`
+// linearly at each new intersection vertex, then re-fans the resulting convex
+// polygon back into the strip. This guarantees the returned mesh fits the
+// requested bounds even though the underlying antialiased trapezoids are not
+// themselves clipped to the clip rect.
+fn clip_tri_strip_to_rect(strip: &[OutputVertex], x: i32, y: i32, width: i32, height: i32) -> Box<[OutputVertex]> {
+    let min = (x as f32, y as f32);
+    let max = ((x + width) as f32, (y + height) as f32);
+
+    let inside = |v: &OutputVertex| v.x >= min.0 && v.x <= max.0 && v.y >= min.1 && v.y <= max.1;
+    if strip.iter().all(inside) {
+        // Nothing needs clipping: leave the buffer exactly as the rasterizer
+        // produced it rather than paying for a needless re-stitch.
+        return strip.to_vec().into_boxed_slice();
+    }
+
+    let mut out: Vec<OutputVertex> = Vec::with_capacity(strip.len());
+    for (a, b, c) in tri_strip_triangles(strip) {
+        if inside(&a) && inside(&b) && inside(&c) {
+            append_triangle(&mut out, a, b, c);
+            continue;
+        }
+        for tri in clip_triangle_to_rect(a, b, c, min, max).chunks(3) {
+            append_triangle(&mut out, tri[0], tri[1], tri[2]);
+        }
+    }
+    out.into_boxed_slice()
+}
+
+// Appends a triangle to a triangle-strip buffer being built up one (possibly
+// re-wound) triangle at a time, bridging from the previous triangle with a
+// degenerate pair so `tri_strip_triangles` skips the seam between them.
+//
+// `tri_strip_triangles` alternates winding by absolute position (even index:
+// unchanged order, odd index: first two vertices swapped) the way a real
+// GL_TRIANGLE_STRIP does. That convention only produces the right winding when
+// consecutive triangles genuinely share an edge; here each triangle is
+// independent, so instead we choose whichever push order (`a, b, c` or `b, a,
+// c`) will decode back to the original `a, b, c` orientation at the position
+// this triangle actually lands at, which only depends on `out.len() % 2` (the
+// even-length degenerate bridge never changes that parity).
+fn append_triangle(out: &mut Vec<OutputVertex>, a: OutputVertex, b: OutputVertex, c: OutputVertex) {
+    let (p0, p1) = if out.len() % 2 == 0 { (a, b) } else { (b, a) };
+    if let Some(&last) = out.last() {
+        out.push(last);
+        out.push(p0);
+    }
+    out.push(p0);
+    out.push(p1);
+    out.push(c);
+}
+
+fn lerp_vertex(a: &OutputVertex, b: &OutputVertex, t: f32) -> OutputVertex {
+    OutputVertex {
+        x: a.x + (b.x - a.x) * t,
+        y: a.y + (b.y - a.y) * t,
+        coverage: a.coverage + (b.coverage - a.coverage) * t,
+    }
+}
+
+fn clip_polygon_edge(poly: &[OutputVertex], inside: impl Fn(&OutputVertex) -> bool, t_to_edge: impl Fn(&OutputVertex, &OutputVertex) -> f32) -> Vec<OutputVertex> {
+    if poly.is_empty() {
+        return Vec::new();
+    }
+    let mut out = Vec::with_capacity(poly.len() + 1);
+    for i in 0..poly.len() {
+        let curr = poly[i];
+        let prev = poly[(i + poly.len() - 1) % poly.len()];
+        let curr_in = inside(&curr);
+        let prev_in = inside(&prev);
+        if curr_in != prev_in {
+            out.push(lerp_vertex(&prev, &curr, t_to_edge(&prev, &curr)));
+        }
+        if curr_in {
+            out.push(curr);
+        }
+    }
+    out
+}
+
+// Clips a single triangle to the rectangle `[min, max]`, returning zero or more
+// triangles (as a flat vertex list, 3 per triangle) fanned from the resulting
+// convex polygon.
+fn clip_triangle_to_rect(a: OutputVertex, b: OutputVertex, c: OutputVertex, min: (f32, f32), max: (f32, f32)) -> Vec<OutputVertex> {
+    let mut poly = vec![a, b, c];
+    poly = clip_polygon_edge(&poly, |v| v.x >= min.0, |p, c| (min.0 - p.x) / (c.x - p.x));
+    poly = clip_polygon_edge(&poly, |v| v.x <= max.0, |p, c| (max.0 - p.x) / (c.x - p.x));
+    poly = clip_polygon_edge(&poly, |v| v.y >= min.1, |p, c| (min.1 - p.y) / (c.y - p.y));
+    poly = clip_polygon_edge(&poly, |v| v.y <= max.1, |p, c| (max.1 - p.y) / (c.y - p.y));
+
+    if poly.len() < 3 {
+        return Vec::new();
+    }
+    let mut out = Vec::with_capacity((poly.len() - 2) * 3);
+    for i in 1..poly.len() - 1 {
+        out.push(poly[0]);
+        out.push(poly[i]);
+        out.push(poly[i + 1]);
+    }
+    out
+}
+
+/// Like `rasterize_to_tri_strip`, but returns a deduplicated vertex buffer together
+/// with a triangle index buffer instead of a triangle strip.
+pub fn rasterize_to_indexed_mesh(
+    fill_mode: FillMode,
+    types: &[BYTE],
+    points: &[POINT],
+    clip_x: i32,
+    clip_y: i32,
+    clip_width: i32,
+    clip_height: i32,
+    need_inside: bool,
+    need_outside: bool,
+) -> (Box<[OutputVertex]>, Box<[u32]>) {
+    let strip = rasterize_to_tri_strip(fill_mode, types, points, clip_x, clip_y, clip_width, clip_height, need_inside, need_outside);
+    tri_strip_to_indexed_mesh(&strip)
+}
+
+// Decodes a triangle-strip vertex buffer (as produced by `rasterize_to_tri_strip`)
+// into its logical triangles, dropping the degenerate zero-area triangles used to
+// bridge/restart the strip (vertex buffers produced this way share a vertex pair
+// between any such bridge and its neighbors).
+fn tri_strip_triangles(strip: &[OutputVertex]) -> impl Iterator<Item = (OutputVertex, OutputVertex, OutputVertex)> + '_ {
+    (2..strip.len()).filter_map(move |i| {
+        let (a, b, c) = if i % 2 == 0 {
+            (strip[i - 2], strip[i - 1], strip[i])
+        } else {
+            (strip[i - 1], strip[i - 2], strip[i])
+        };
+        if a == b || b == c || a == c {
+            None
+        } else {
+            Some((a, b, c))
+        }
+    })
+}
+
+// Converts a triangle-strip vertex buffer into a deduplicated vertex buffer plus
+// a triangle index buffer.
+fn tri_strip_to_indexed_mesh(strip: &[OutputVertex]) -> (Box<[OutputVertex]>, Box<[u32]>) {
+    let mut vertices: Vec<OutputVertex> = Vec::new();
+    let mut interned: HashMap<OutputVertex, u32> = HashMap::new();
+    let mut indices: Vec<u32> = Vec::new();
+
+    fn intern(v: OutputVertex, vertices: &mut Vec<OutputVertex>, interned: &mut HashMap<OutputVertex, u32>) -> u32 {
+        *interned.entry(v).or_insert_with(|| {
+            vertices.push(v);
+            (vertices.len() - 1) as u32
+        })
+    }
+
+    for (a, b, c) in tri_strip_triangles(strip) {
+        let ia = intern(a, &mut vertices, &mut interned);
+        let ib = intern(b, &mut vertices, &mut interned);
+        let ic = intern(c, &mut vertices, &mut interned);
+        indices.push(ia);
+        indices.push(ib);
+        indices.push(ic);
+    }
+
+    (vertices.into_boxed_slice(), indices.into_boxed_slice())
 }
 
 #[cfg(test)]
@@ -583,4 +867,203 @@ mod tests {
         let result = p.rasterize_to_tri_strip(0, 0, 100, 100);
         assert_eq!(result.len(), 0);
     }
+
+    #[test]
+    fn svg_path_basic_rect_matches_manual_path() {
+        let p = PathBuilder::from_svg_path("M10,10 L40,10 L40,40 L10,40 Z").unwrap();
+        let result = p.rasterize_to_tri_strip(0, 0, 100, 100);
+
+        let mut manual = PathBuilder::new();
+        manual.move_to(10., 10.);
+        manual.line_to(40., 10.);
+        manual.line_to(40., 40.);
+        manual.line_to(10., 40.);
+        manual.close();
+        let expected = manual.rasterize_to_tri_strip(0, 0, 100, 100);
+
+        assert_eq!(calculate_hash(&result), calculate_hash(&expected));
+    }
+
+    #[test]
+    fn svg_path_rejects_stray_character_after_close() {
+        // A `Z`/`z` command takes no arguments, so it can't begin a repeated
+        // argument group; a stray character after it must be rejected instead of
+        // being treated as an (infinite) repetition of `Z`.
+        assert!(PathBuilder::from_svg_path("M0 0Z1").is_err());
+    }
+
+    #[test]
+    fn clipped_to_rect() {
+        let mut p = PathBuilder::new();
+        p.move_to(-50., -50.);
+        p.line_to(150., -50.);
+        p.line_to(150., 150.);
+        p.line_to(-50., 150.);
+        p.close();
+        let result = p.rasterize_to_tri_strip(0, 0, 100, 100);
+        assert!(!result.is_empty());
+        for v in result.iter() {
+            assert!(v.x >= 0.0 && v.x <= 100.0, "x {} out of clip bounds", v.x);
+            assert!(v.y >= 0.0 && v.y <= 100.0, "y {} out of clip bounds", v.y);
+        }
+    }
+
+    #[test]
+    fn clipped_to_rect_matches_unclipped_when_fully_inside() {
+        let mut p = PathBuilder::new();
+        p.move_to(10., 10.);
+        p.line_to(10., 30.);
+        p.line_to(30., 30.);
+        p.line_to(30., 10.);
+        p.close();
+        let result = p.rasterize_to_tri_strip(0, 0, 100, 100);
+        assert_eq!(result.len(), 10);
+        assert_eq!(dbg!(calculate_hash(&result)), 0x5851570566450135);
+    }
+
+    #[test]
+    fn stroke_basic() {
+        // A single straight horizontal segment with the default `Butt` cap
+        // strokes to exactly the rectangle obtained by offsetting the
+        // segment by `width / 2` to either side, so its rasterized output
+        // must match that rectangle filled directly (with the `Winding`
+        // fill mode `rasterize_stroke_to_tri_strip` uses internally).
+        let mut p = PathBuilder::new();
+        p.move_to(10., 10.);
+        p.line_to(40., 10.);
+        let style = StrokeStyle { width: 4.0, ..StrokeStyle::default() };
+        let result = p.rasterize_stroke_to_tri_strip(&style, 0, 0, 100, 100);
+
+        let mut expected = PathBuilder::new();
+        expected.set_fill_mode(FillMode::Winding);
+        expected.move_to(10., 12.);
+        expected.line_to(40., 12.);
+        expected.line_to(40., 8.);
+        expected.line_to(10., 8.);
+        expected.close();
+        let expected_result = expected.rasterize_to_tri_strip(0, 0, 100, 100);
+
+        assert_eq!(calculate_hash(&result), calculate_hash(&expected_result));
+    }
+
+    #[test]
+    fn indexed_mesh_matches_tri_strip_triangle_count() {
+        let mut p = PathBuilder::new();
+        p.move_to(10., 10.);
+        p.line_to(40., 10.);
+        p.line_to(40., 40.);
+        p.line_to(10., 40.);
+        p.close();
+        let strip = p.rasterize_to_tri_strip(0, 0, 100, 100);
+        // `rasterize_to_indexed_mesh` decodes the same triangle sequence as
+        // `tri_strip_triangles`, just deduplicated through an index buffer:
+        // re-expanding the indices must reproduce that sequence exactly.
+        let expected: Vec<(OutputVertex, OutputVertex, OutputVertex)> = tri_strip_triangles(&strip).collect();
+
+        let (vertices, indices) = p.rasterize_to_indexed_mesh(0, 0, 100, 100);
+        assert_eq!(indices.len(), expected.len() * 3);
+        assert!(vertices.len() <= strip.len());
+        let actual: Vec<(OutputVertex, OutputVertex, OutputVertex)> = indices
+            .chunks(3)
+            .map(|tri| (vertices[tri[0] as usize], vertices[tri[1] as usize], vertices[tri[2] as usize]))
+            .collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn set_transform_moves_fill_and_stroke_output() {
+        let mut identity = PathBuilder::new();
+        identity.move_to(10., 10.);
+        identity.line_to(40., 10.);
+        identity.line_to(40., 40.);
+        identity.line_to(10., 40.);
+        identity.close();
+        let untransformed_fill = identity.rasterize_to_tri_strip(0, 0, 100, 100);
+        let style = StrokeStyle { width: 4.0, ..StrokeStyle::default() };
+        let untransformed_stroke = identity.rasterize_stroke_to_tri_strip(&style, 0, 0, 100, 100);
+
+        let mut translated = PathBuilder::new();
+        translated.set_transform(1., 0., 0., 1., 20., 0.);
+        translated.move_to(10., 10.);
+        translated.line_to(40., 10.);
+        translated.line_to(40., 40.);
+        translated.line_to(10., 40.);
+        translated.close();
+        let translated_fill = translated.rasterize_to_tri_strip(0, 0, 100, 100);
+        let translated_stroke = translated.rasterize_stroke_to_tri_strip(&style, 0, 0, 100, 100);
+
+        // A pure x-translation by 20 must reproduce the untransformed output
+        // shifted by exactly 20 units in x, not just "some" different output.
+        let shift = |vs: &[OutputVertex]| -> Vec<OutputVertex> {
+            vs.iter().map(|v| OutputVertex { x: v.x + 20.0, y: v.y, coverage: v.coverage }).collect()
+        };
+        assert_eq!(calculate_hash(&translated_fill), calculate_hash(&shift(&untransformed_fill)));
+        assert_eq!(calculate_hash(&translated_stroke), calculate_hash(&shift(&untransformed_stroke)));
+    }
+
+    #[test]
+    fn loop_blinn_mesh_basic() {
+        // `quad_to` elevates exactly to the cubic `curve_to` takes, so
+        // `cubic_to_quads` reconstructs this as a single quad span with no
+        // subdivision, making the output fully predictable: the line span
+        // touches the fan origin (0, 0) and is skipped, leaving only the
+        // quad span's control triangle plus its chord-to-origin wedge.
+        let mut p = PathBuilder::new();
+        p.move_to(0., 0.);
+        p.line_to(10., 0.);
+        p.quad_to(15., 5., 10., 10.);
+        p.close();
+        let result = p.rasterize_to_loop_blinn_mesh();
+
+        let expected = vec![
+            LoopBlinnVertex { x: 10., y: 0., coverage: 1.0, klm: [0.0, 0.0, 1.0] },
+            LoopBlinnVertex { x: 15., y: 5., coverage: 1.0, klm: [0.5, 0.0, 1.0] },
+            LoopBlinnVertex { x: 10., y: 10., coverage: 1.0, klm: [1.0, 1.0, 1.0] },
+            LoopBlinnVertex { x: 0., y: 0., coverage: 1.0, klm: [-1.0, 0.0, 0.0] },
+            LoopBlinnVertex { x: 10., y: 0., coverage: 1.0, klm: [-1.0, 0.0, 0.0] },
+            LoopBlinnVertex { x: 10., y: 10., coverage: 1.0, klm: [-1.0, 0.0, 0.0] },
+        ];
+        assert_eq!(result.as_ref(), expected.as_slice());
+    }
+
+    // Finds the first curve control triangle (vertices with the canonical
+    // (0,0), (1/2,0), (1,1) `klm` pattern, as opposed to the (-1,0,0) used by
+    // plain interior/fan triangles) and returns its `klm.z` sign.
+    fn control_triangle_sign(mesh: &[LoopBlinnVertex]) -> Option<f32> {
+        mesh.chunks(3).find_map(|tri| {
+            if tri[0].klm[0] != -1.0 {
+                Some(tri[0].klm[2])
+            } else {
+                None
+            }
+        })
+    }
+
+    #[test]
+    fn loop_blinn_convex_curve_adds_area() {
+        // A curve that bulges away from the fan origin (an outward-rounded
+        // corner) must add the sliver between the chord and the curve.
+        let mut p = PathBuilder::new();
+        p.move_to(0., 0.);
+        p.line_to(10., 0.);
+        p.quad_to(15., 10., 10., 10.);
+        p.close();
+        let mesh = p.rasterize_to_loop_blinn_mesh();
+        assert_eq!(control_triangle_sign(&mesh), Some(1.0));
+    }
+
+    #[test]
+    fn loop_blinn_concave_curve_subtracts_area() {
+        // A square whose top edge bulges down toward the fan origin, cutting
+        // a concave notch into the fill: the control triangle must subtract
+        // area instead of adding it, or the notch silently fills in.
+        let mut p = PathBuilder::new();
+        p.move_to(0., 0.);
+        p.line_to(10., 0.);
+        p.line_to(10., 10.);
+        p.quad_to(5., 6., 0., 10.);
+        p.close();
+        let mesh = p.rasterize_to_loop_blinn_mesh();
+        assert_eq!(control_triangle_sign(&mesh), Some(-1.0));
+    }
 }
@@ -0,0 +1,223 @@
+// Loop-Blinn curve rendering: rather than flattening curve segments into many line
+// segments for the antialiased fill rasterizer, each curve span is emitted as a
+// single control triangle carrying per-vertex implicit-curve (klm) texture
+// coordinates. A fragment shader tests `klm.x * klm.x - klm.y < 0` (scaled by the
+// sign baked into klm.z) to resolve the curve edge at native resolution, instead of
+// at the resolution of the flattened polyline.
+//
+// See Loop & Blinn, "Resolution Independent Curve Rendering using Programmable
+// Graphics Hardware" (SIGGRAPH 2005).
+
+use crate::stroke::PathCommand;
+
+/// A vertex for the Loop-Blinn output mesh. Interior fill vertices use
+/// `klm = (-1, 0, 0)`: their `klm.z` is 0 rather than the ±1 carried by curve
+/// vertices (the `u*u - v` value itself is 1 for them, not negative);
+/// curve control triangle vertices get the (0,0), (1/2,0), (1,1)
+/// coordinates described in Loop & Blinn, scaled by `klm.z = ±1` so a convex
+/// span (bulging away from the fan origin) adds the sliver between the chord
+/// and the curve, while a concave span (bulging toward the fan origin, e.g.
+/// an inward notch) subtracts it instead.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct LoopBlinnVertex {
+    pub x: f32,
+    pub y: f32,
+    pub coverage: f32,
+    pub klm: [f32; 3],
+}
+
+/// Selects between the existing flattened analytic-AA output and the Loop-Blinn
+/// curve output.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CurveRenderingMode {
+    Flattened,
+    LoopBlinn,
+}
+
+impl Default for CurveRenderingMode {
+    fn default() -> Self {
+        CurveRenderingMode::Flattened
+    }
+}
+
+const INTERIOR_KLM: [f32; 3] = [-1.0, 0.0, 0.0];
+
+// Maximum allowed deviation, in path coordinates, between the cubic being
+// approximated and the quadratic span standing in for it.
+const CUBIC_TO_QUAD_TOLERANCE: f32 = 0.1;
+const MAX_SUBDIVISION_DEPTH: u32 = 12;
+
+enum Span {
+    Line((f32, f32), (f32, f32)),
+    // Quadratic control triangle: p0 (start), p1 (control), p2 (end).
+    Quad((f32, f32), (f32, f32), (f32, f32)),
+}
+
+struct FlatSubpath {
+    start: (f32, f32),
+    spans: Vec<Span>,
+}
+
+fn mid(a: (f32, f32), b: (f32, f32)) -> (f32, f32) {
+    ((a.0 + b.0) * 0.5, (a.1 + b.1) * 0.5)
+}
+
+fn dist(a: (f32, f32), b: (f32, f32)) -> f32 {
+    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
+}
+
+// Twice the signed area of triangle (a, b, c); positive/negative indicates
+// which side of the directed line a->b that c falls on.
+fn signed_area2(a: (f32, f32), b: (f32, f32), c: (f32, f32)) -> f32 {
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+// Approximates a cubic Bezier with a single quadratic by matching the curve at
+// its endpoints and midpoint, recursing when the two deviate anywhere by more
+// than `CUBIC_TO_QUAD_TOLERANCE`.
+fn cubic_to_quads(p0: (f32, f32), p1: (f32, f32), p2: (f32, f32), p3: (f32, f32), depth: u32, out: &mut Vec<Span>) {
+    // Candidate single-quadratic control point: the classic midpoint-matching
+    // approximation, Q = (3*(P1 + P2) - (P0 + P3)) / 4.
+    let q = ((3.0 * (p1.0 + p2.0) - (p0.0 + p3.0)) / 4.0, (3.0 * (p1.1 + p2.1) - (p0.1 + p3.1)) / 4.0);
+
+    // The cubic minus this quadratic is D * t*(t - 1/2)*(t - 1) with
+    // D = P3 - 3*P2 + 3*P1 - P0, so the two always agree at t = 1/2 and their
+    // largest deviation over [0, 1] is sqrt(3)/36 * |D|.
+    let d = (p3.0 - 3.0 * p2.0 + 3.0 * p1.0 - p0.0, p3.1 - 3.0 * p2.1 + 3.0 * p1.1 - p0.1);
+    let max_error = 3.0f32.sqrt() / 36.0 * (d.0 * d.0 + d.1 * d.1).sqrt();
+
+    if depth >= MAX_SUBDIVISION_DEPTH || max_error <= CUBIC_TO_QUAD_TOLERANCE {
+        out.push(Span::Quad(p0, q, p3));
+        return;
+    }
+
+    // Split the cubic at t = 0.5 and recurse on each half.
+    let p01 = mid(p0, p1);
+    let p12 = mid(p1, p2);
+    let p23 = mid(p2, p3);
+    let p012 = mid(p01, p12);
+    let p123 = mid(p12, p23);
+    let p0123 = mid(p012, p123);
+    cubic_to_quads(p0, p01, p012, p0123, depth + 1, out);
+    cubic_to_quads(p0123, p123, p23, p3, depth + 1, out);
+}
+
+fn build_subpaths(commands: &[PathCommand]) -> Vec<FlatSubpath> {
+    let mut subpaths = Vec::new();
+    let mut start: Option<(f32, f32)> = None;
+    let mut cur: (f32, f32) = (0.0, 0.0);
+    let mut spans: Vec<Span> = Vec::new();
+
+    for cmd in commands {
+        match *cmd {
+            PathCommand::MoveTo(x, y) => {
+                if let Some(s) = start {
+                    if !spans.is_empty() {
+                        subpaths.push(FlatSubpath { start: s, spans: std::mem::take(&mut spans) });
+                    }
+                }
+                start = Some((x, y));
+                cur = (x, y);
+            }
+            PathCommand::LineTo(x, y) => {
+                if start.is_none() {
+                    start = Some((x, y));
+                    cur = (x, y);
+                    continue;
+                }
+                spans.push(Span::Line(cur, (x, y)));
+                cur = (x, y);
+            }
+            PathCommand::CurveTo(c1x, c1y, c2x, c2y, x, y) => {
+                if start.is_none() {
+                    start = Some((c1x, c1y));
+                    cur = (c1x, c1y);
+                }
+                cubic_to_quads(cur, (c1x, c1y), (c2x, c2y), (x, y), 0, &mut spans);
+                cur = (x, y);
+            }
+            PathCommand::Close => {
+                if let Some(s) = start {
+                    if (cur.0 - s.0).abs() > 1e-6 || (cur.1 - s.1).abs() > 1e-6 {
+                        spans.push(Span::Line(cur, s));
+                    }
+                    cur = s;
+                }
+            }
+        }
+    }
+    if let Some(s) = start {
+        if !spans.is_empty() {
+            subpaths.push(FlatSubpath { start: s, spans });
+        }
+    }
+    subpaths
+}
+
+fn push_vertex(out: &mut Vec<LoopBlinnVertex>, p: (f32, f32), klm: [f32; 3]) {
+    out.push(LoopBlinnVertex { x: p.0, y: p.1, coverage: 1.0, klm });
+}
+
+// Triangulates each subpath independently as a fan from its start point, which
+// produces a correct fill for a single convex or star-shaped subpath (the
+// common case for glyph and icon outlines). There is no cross-subpath
+// winding/even-odd accounting at all: unlike `rasterize_to_tri_strip`, which
+// can cut a hole into one subpath using a second subpath (see the even-odd
+// case of the `fill_mode` test), this function always fans every subpath as a
+// separate filled region, so a shape built from multiple subpaths renders as
+// several overlapping solid fills rather than a shape with a hole. Callers
+// with multi-subpath input (e.g. a glyph with a counter) should not use this
+// mode if a hole is required.
+pub(crate) fn rasterize_to_loop_blinn_mesh(commands: &[PathCommand]) -> Box<[LoopBlinnVertex]> {
+    let subpaths = build_subpaths(commands);
+    let mut out = Vec::new();
+
+    for subpath in &subpaths {
+        let v0 = subpath.start;
+        for span in &subpath.spans {
+            match *span {
+                Span::Line(a, b) => {
+                    if points_eq(a, v0) || points_eq(b, v0) {
+                        continue;
+                    }
+                    push_vertex(&mut out, v0, INTERIOR_KLM);
+                    push_vertex(&mut out, a, INTERIOR_KLM);
+                    push_vertex(&mut out, b, INTERIOR_KLM);
+                }
+                Span::Quad(p0, p1, p2) => {
+                    // Orientation of the control point relative to the fan
+                    // origin determines whether the curve bulges away from
+                    // the filled region (convex — the sliver between the
+                    // chord and the curve should be added) or toward it
+                    // (concave, e.g. an inward notch — that sliver must be
+                    // subtracted instead, or the notch silently fills in).
+                    let side_v0 = signed_area2(p0, p2, v0);
+                    let side_p1 = signed_area2(p0, p2, p1);
+                    let sign: f32 = if side_v0 * side_p1 > 0.0 { -1.0 } else { 1.0 };
+
+                    // The curve's control triangle: assigns the canonical
+                    // (0,0), (1/2,0), (1,1) coordinates used by the `u*u - v`
+                    // implicit test for a quadratic Bezier, scaled by `sign`.
+                    push_vertex(&mut out, p0, [0.0, 0.0, sign]);
+                    push_vertex(&mut out, p1, [0.5, 0.0, sign]);
+                    push_vertex(&mut out, p2, [1.0, 1.0, sign]);
+
+                    // The interior fan triangle filling between the chord and the
+                    // fan origin; omitted when the chord already touches v0.
+                    if !points_eq(p0, v0) && !points_eq(p2, v0) {
+                        push_vertex(&mut out, v0, INTERIOR_KLM);
+                        push_vertex(&mut out, p0, INTERIOR_KLM);
+                        push_vertex(&mut out, p2, INTERIOR_KLM);
+                    }
+                }
+            }
+        }
+    }
+
+    out.into_boxed_slice()
+}
+
+fn points_eq(a: (f32, f32), b: (f32, f32)) -> bool {
+    (a.0 - b.0).abs() < 1e-6 && (a.1 - b.1).abs() < 1e-6
+}
@@ -0,0 +1,360 @@
+// Converts an open or closed polyline/curve path into a filled outline representing
+// its antialiased stroke, by building an offset polygon and handing it back to the
+// existing winding-fill rasterizer. This avoids needing a separate stroking crate:
+// callers get the same `OutputVertex` triangle output as a regular fill.
+
+use crate::{FillMode, OutputVertex, PathBuilder};
+
+/// How the ends of an open subpath are finished.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LineCap {
+    Butt,
+    Round,
+    Square,
+}
+
+impl Default for LineCap {
+    fn default() -> Self {
+        LineCap::Butt
+    }
+}
+
+/// How two consecutive segments are connected.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LineJoin {
+    Miter,
+    Bevel,
+    Round,
+}
+
+impl Default for LineJoin {
+    fn default() -> Self {
+        LineJoin::Miter
+    }
+}
+
+/// Parameters controlling how `PathBuilder::rasterize_stroke_to_tri_strip` turns
+/// a path into a filled outline.
+#[derive(Copy, Clone, Debug)]
+pub struct StrokeStyle {
+    pub width: f32,
+    pub cap: LineCap,
+    pub join: LineJoin,
+    /// Miter joins longer than `miter_limit * width` fall back to a bevel join.
+    pub miter_limit: f32,
+}
+
+impl Default for StrokeStyle {
+    fn default() -> Self {
+        Self {
+            width: 1.0,
+            cap: LineCap::Butt,
+            join: LineJoin::Miter,
+            miter_limit: 10.0,
+        }
+    }
+}
+
+// Maximum flattening error, in the same units as the incoming path coordinates,
+// used when turning curve_to spans into polylines for offsetting.
+const FLATTEN_TOLERANCE: f32 = 0.1;
+const MAX_FLATTEN_RECURSION: u32 = 16;
+
+#[derive(Copy, Clone)]
+pub(crate) enum PathCommand {
+    MoveTo(f32, f32),
+    LineTo(f32, f32),
+    CurveTo(f32, f32, f32, f32, f32, f32),
+    Close,
+}
+
+struct FlatSubpath {
+    points: Vec<(f32, f32)>,
+    closed: bool,
+}
+
+fn flatten_cubic(p0: (f32, f32), p1: (f32, f32), p2: (f32, f32), p3: (f32, f32), depth: u32, out: &mut Vec<(f32, f32)>) {
+    // Flatness test: distance of the control points from the chord p0-p3.
+    let chord = (p3.0 - p0.0, p3.1 - p0.1);
+    let chord_len2 = chord.0 * chord.0 + chord.1 * chord.1;
+    let dist = |p: (f32, f32)| -> f32 {
+        if chord_len2 < 1e-12 {
+            ((p.0 - p0.0).powi(2) + (p.1 - p0.1).powi(2)).sqrt()
+        } else {
+            ((p.0 - p0.0) * chord.1 - (p.1 - p0.1) * chord.0).abs() / chord_len2.sqrt()
+        }
+    };
+    if depth >= MAX_FLATTEN_RECURSION || (dist(p1) <= FLATTEN_TOLERANCE && dist(p2) <= FLATTEN_TOLERANCE) {
+        out.push(p3);
+        return;
+    }
+    // De Casteljau subdivision at t = 0.5.
+    let p01 = mid(p0, p1);
+    let p12 = mid(p1, p2);
+    let p23 = mid(p2, p3);
+    let p012 = mid(p01, p12);
+    let p123 = mid(p12, p23);
+    let p0123 = mid(p012, p123);
+    flatten_cubic(p0, p01, p012, p0123, depth + 1, out);
+    flatten_cubic(p0123, p123, p23, p3, depth + 1, out);
+}
+
+fn mid(a: (f32, f32), b: (f32, f32)) -> (f32, f32) {
+    ((a.0 + b.0) * 0.5, (a.1 + b.1) * 0.5)
+}
+
+fn flatten_subpaths(commands: &[PathCommand]) -> Vec<FlatSubpath> {
+    let mut subpaths = Vec::new();
+    let mut current: Vec<(f32, f32)> = Vec::new();
+    let mut closed = false;
+    for cmd in commands {
+        match *cmd {
+            PathCommand::MoveTo(x, y) => {
+                if current.len() > 1 {
+                    subpaths.push(FlatSubpath { points: std::mem::take(&mut current), closed });
+                } else {
+                    current.clear();
+                }
+                closed = false;
+                current.push((x, y));
+            }
+            PathCommand::LineTo(x, y) => {
+                if current.is_empty() {
+                    current.push((x, y));
+                } else {
+                    current.push((x, y));
+                }
+            }
+            PathCommand::CurveTo(c1x, c1y, c2x, c2y, x, y) => {
+                let start = *current.last().unwrap_or(&(c1x, c1y));
+                if current.is_empty() {
+                    current.push(start);
+                }
+                flatten_cubic(start, (c1x, c1y), (c2x, c2y), (x, y), 0, &mut current);
+            }
+            PathCommand::Close => {
+                closed = true;
+            }
+        }
+    }
+    if current.len() > 1 {
+        subpaths.push(FlatSubpath { points: current, closed });
+    }
+    subpaths
+}
+
+fn dedup_points(points: &[(f32, f32)]) -> Vec<(f32, f32)> {
+    let mut out: Vec<(f32, f32)> = Vec::with_capacity(points.len());
+    for &p in points {
+        if out.last().map_or(true, |&last| (last.0 - p.0).abs() > 1e-6 || (last.1 - p.1).abs() > 1e-6) {
+            out.push(p);
+        }
+    }
+    out
+}
+
+fn normalize(v: (f32, f32)) -> (f32, f32) {
+    let len = (v.0 * v.0 + v.1 * v.1).sqrt();
+    if len < 1e-12 {
+        (0.0, 0.0)
+    } else {
+        (v.0 / len, v.1 / len)
+    }
+}
+
+// Left-hand perpendicular of a unit vector.
+fn perp(v: (f32, f32)) -> (f32, f32) {
+    (-v.1, v.0)
+}
+
+fn add(a: (f32, f32), b: (f32, f32)) -> (f32, f32) {
+    (a.0 + b.0, a.1 + b.1)
+}
+
+fn scale(a: (f32, f32), s: f32) -> (f32, f32) {
+    (a.0 * s, a.1 * s)
+}
+
+// Appends the join geometry (if any) that bridges the end of one offset segment to
+// the start of the next, as seen from the outer side of the turn at `center`.
+fn append_join(out: &mut Vec<(f32, f32)>, center: (f32, f32), prev_end: (f32, f32), next_start: (f32, f32), half_width: f32, join: LineJoin, miter_limit: f32) {
+    match join {
+        LineJoin::Bevel => {
+            out.push(prev_end);
+            out.push(next_start);
+        }
+        LineJoin::Round => {
+            out.push(prev_end);
+            let v0 = normalize((prev_end.0 - center.0, prev_end.1 - center.1));
+            let v1 = normalize((next_start.0 - center.0, next_start.1 - center.1));
+            let angle0 = v0.1.atan2(v0.0);
+            let angle1 = v1.1.atan2(v1.0);
+            // Walk the short way around the join.
+            let mut delta = angle1 - angle0;
+            while delta > std::f32::consts::PI {
+                delta -= 2.0 * std::f32::consts::PI;
+            }
+            while delta < -std::f32::consts::PI {
+                delta += 2.0 * std::f32::consts::PI;
+            }
+            let steps = ((delta.abs() / 0.3).ceil() as i32).max(1);
+            for i in 1..steps {
+                let t = angle0 + delta * (i as f32 / steps as f32);
+                out.push(add(center, scale((t.cos(), t.sin()), half_width)));
+            }
+            out.push(next_start);
+        }
+        LineJoin::Miter => {
+            let d0 = normalize((prev_end.0 - center.0, prev_end.1 - center.1));
+            let d1 = normalize((next_start.0 - center.0, next_start.1 - center.1));
+            // Tangent directions along each offset edge (perpendicular to the radius).
+            let t0 = perp(d0);
+            let t1 = perp(d1);
+            // Solve prev_end + s*t0 == next_start + u*t1 for the miter apex.
+            let denom = t0.0 * t1.1 - t0.1 * t1.0;
+            if denom.abs() > 1e-6 {
+                let diff = (next_start.0 - prev_end.0, next_start.1 - prev_end.1);
+                let s = (diff.0 * t1.1 - diff.1 * t1.0) / denom;
+                let apex = add(prev_end, scale(t0, s));
+                let miter_len = ((apex.0 - center.0).powi(2) + (apex.1 - center.1).powi(2)).sqrt();
+                if miter_len <= miter_limit * half_width.max(1e-6) {
+                    out.push(prev_end);
+                    out.push(apex);
+                    out.push(next_start);
+                    return;
+                }
+            }
+            // Miter too long (or degenerate): fall back to a bevel.
+            out.push(prev_end);
+            out.push(next_start);
+        }
+    }
+}
+
+// Offsets `points` by `half_width` to one side (sign = +1.0 / -1.0) and inserts join
+// geometry at interior vertices (and, for closed subpaths, at the wrap-around vertex).
+fn offset_side(points: &[(f32, f32)], closed: bool, half_width: f32, join: LineJoin, miter_limit: f32, sign: f32) -> Vec<(f32, f32)> {
+    let n = points.len();
+    let mut out = Vec::with_capacity(n * 2);
+    let segment_count = if closed { n } else { n - 1 };
+    let mut prev_offset_end: Option<(f32, f32)> = None;
+    for i in 0..segment_count {
+        let a = points[i % n];
+        let b = points[(i + 1) % n];
+        let dir = normalize((b.0 - a.0, b.1 - a.1));
+        let offset = scale(perp(dir), half_width * sign);
+        let a_off = add(a, offset);
+        let b_off = add(b, offset);
+        if let Some(prev_end) = prev_offset_end {
+            append_join(&mut out, a, prev_end, a_off, half_width, join, miter_limit);
+        } else {
+            out.push(a_off);
+        }
+        out.push(b_off);
+        prev_offset_end = Some(b_off);
+    }
+    if closed {
+        // Close the join between the last and first segment.
+        if let Some(prev_end) = prev_offset_end {
+            let first = out.remove(0);
+            append_join(&mut out, points[0], prev_end, first, half_width, join, miter_limit);
+        }
+    }
+    out
+}
+
+fn append_cap(out: &mut Vec<(f32, f32)>, point: (f32, f32), from: (f32, f32), to: (f32, f32), half_width: f32, cap: LineCap) {
+    match cap {
+        LineCap::Butt => {
+            out.push(from);
+            out.push(to);
+        }
+        LineCap::Square => {
+            let dir = normalize((to.0 - from.0, to.1 - from.1));
+            // The outward tangent at this end of the path.
+            let out_dir = perp(dir);
+            let out_dir = if (out_dir.0 * (from.0 - point.0) + out_dir.1 * (from.1 - point.1)) < 0.0 {
+                scale(out_dir, -1.0)
+            } else {
+                out_dir
+            };
+            out.push(from);
+            out.push(add(from, scale(out_dir, half_width)));
+            out.push(add(to, scale(out_dir, half_width)));
+            out.push(to);
+        }
+        LineCap::Round => {
+            out.push(from);
+            let v0 = normalize((from.0 - point.0, from.1 - point.1));
+            let v1 = normalize((to.0 - point.0, to.1 - point.1));
+            let angle0 = v0.1.atan2(v0.0);
+            let mut angle1 = v1.1.atan2(v1.0);
+            // The outline reaches each cap going from the +perp side to the
+            // -perp side, so sweeping clockwise passes the outward end.
+            if angle1 > angle0 {
+                angle1 -= 2.0 * std::f32::consts::PI;
+            }
+            let delta = angle1 - angle0;
+            let steps = ((delta.abs() / 0.3).ceil() as i32).max(1);
+            for i in 1..steps {
+                let t = angle0 + delta * (i as f32 / steps as f32);
+                out.push(add(point, scale((t.cos(), t.sin()), half_width)));
+            }
+            out.push(to);
+        }
+    }
+}
+
+// Builds the fill outline(s) for a single flattened subpath.
+fn stroke_subpath_outline(subpath: &FlatSubpath, style: &StrokeStyle) -> Vec<Vec<(f32, f32)>> {
+    let points = dedup_points(&subpath.points);
+    if points.len() < 2 {
+        return Vec::new();
+    }
+    let half_width = style.width * 0.5;
+
+    if subpath.closed {
+        let outer = offset_side(&points, true, half_width, style.join, style.miter_limit, 1.0);
+        let mut inner = offset_side(&points, true, half_width, style.join, style.miter_limit, -1.0);
+        inner.reverse();
+        vec![outer, inner]
+    } else {
+        let left = offset_side(&points, false, half_width, style.join, style.miter_limit, 1.0);
+        let mut right = offset_side(&points, false, half_width, style.join, style.miter_limit, -1.0);
+        right.reverse();
+
+        let mut outline = Vec::with_capacity(left.len() + right.len() + 8);
+        outline.extend(left.iter().copied());
+        append_cap(&mut outline, *points.last().unwrap(), *left.last().unwrap(), *right.first().unwrap(), half_width, style.cap);
+        outline.extend(right.iter().copied());
+        append_cap(&mut outline, points[0], *right.last().unwrap(), *left.first().unwrap(), half_width, style.cap);
+        vec![outline]
+    }
+}
+
+pub(crate) fn rasterize_stroke_to_tri_strip(
+    commands: &[PathCommand],
+    style: &StrokeStyle,
+    clip_x: i32,
+    clip_y: i32,
+    clip_width: i32,
+    clip_height: i32,
+) -> Box<[OutputVertex]> {
+    let subpaths = flatten_subpaths(commands);
+
+    let mut fill = PathBuilder::new();
+    fill.set_fill_mode(FillMode::Winding);
+    for subpath in &subpaths {
+        for outline in stroke_subpath_outline(subpath, style) {
+            if outline.len() < 3 {
+                continue;
+            }
+            fill.move_to(outline[0].0, outline[0].1);
+            for &(x, y) in &outline[1..] {
+                fill.line_to(x, y);
+            }
+            fill.close();
+        }
+    }
+    fill.rasterize_to_tri_strip(clip_x, clip_y, clip_width, clip_height)
+}
@@ -0,0 +1,397 @@
+// Parses the mini-language used by SVG's `d` path attribute and drives it through
+// `PathBuilder`, so callers can feed web/SVG geometry straight into the rasterizer
+// instead of manually issuing move/line/curve calls.
+
+use crate::PathBuilder;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    UnexpectedCharacter(char, usize),
+    UnexpectedEnd,
+    InvalidNumber(String),
+    MissingCommand,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::UnexpectedCharacter(c, pos) => write!(f, "unexpected character '{}' at offset {}", c, pos),
+            ParseError::UnexpectedEnd => write!(f, "unexpected end of path data"),
+            ParseError::InvalidNumber(s) => write!(f, "invalid number: '{}'", s),
+            ParseError::MissingCommand => write!(f, "path data must start with a command"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+struct Cursor<'a> {
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+    src: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(src: &'a str) -> Self {
+        Self { chars: src.char_indices().peekable(), src }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().map(|&(_, c)| c)
+    }
+
+    fn skip_whitespace_and_commas(&mut self) {
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() || c == ',' {
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn next_command(&mut self) -> Option<(char, usize)> {
+        self.skip_whitespace_and_commas();
+        self.chars.next().map(|(pos, c)| (c, pos))
+    }
+
+    fn peek_is_command_start(&mut self) -> bool {
+        self.skip_whitespace_and_commas();
+        match self.peek() {
+            Some(c) => c.is_ascii_alphabetic() && c != 'e' && c != 'E',
+            None => true,
+        }
+    }
+
+    // Peeks the next character together with its byte offset, for error reporting.
+    fn peek_with_pos(&mut self) -> Option<(char, usize)> {
+        self.skip_whitespace_and_commas();
+        self.chars.peek().map(|&(pos, c)| (c, pos))
+    }
+
+    fn parse_number(&mut self) -> Result<f32, ParseError> {
+        self.skip_whitespace_and_commas();
+        let start = match self.chars.peek() {
+            Some(&(pos, _)) => pos,
+            None => return Err(ParseError::UnexpectedEnd),
+        };
+        let mut end = start;
+        let mut seen_digit = false;
+        if let Some(&(_, c)) = self.chars.peek() {
+            if c == '+' || c == '-' {
+                self.chars.next();
+            }
+        }
+        while let Some(&(pos, c)) = self.chars.peek() {
+            if c.is_ascii_digit() {
+                seen_digit = true;
+                end = pos + c.len_utf8();
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+        if let Some(&(pos, c)) = self.chars.peek() {
+            if c == '.' {
+                end = pos + c.len_utf8();
+                self.chars.next();
+                while let Some(&(pos, c)) = self.chars.peek() {
+                    if c.is_ascii_digit() {
+                        seen_digit = true;
+                        end = pos + c.len_utf8();
+                        self.chars.next();
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+        if !seen_digit {
+            return Err(ParseError::InvalidNumber(self.src[start..end.max(start + 1).min(self.src.len())].to_string()));
+        }
+        if let Some(&(_, c)) = self.chars.peek() {
+            if c == 'e' || c == 'E' {
+                let mut exp_end = end;
+                let mut tmp = self.chars.clone();
+                tmp.next();
+                if let Some(&(pos, c)) = tmp.peek() {
+                    if c == '+' || c == '-' {
+                        exp_end = pos + c.len_utf8();
+                        tmp.next();
+                    }
+                }
+                let mut any_exp_digit = false;
+                while let Some(&(pos, c)) = tmp.peek() {
+                    if c.is_ascii_digit() {
+                        any_exp_digit = true;
+                        exp_end = pos + c.len_utf8();
+                        tmp.next();
+                    } else {
+                        break;
+                    }
+                }
+                if any_exp_digit {
+                    end = exp_end;
+                    self.chars = tmp;
+                }
+            }
+        }
+        self.src[start..end]
+            .parse::<f32>()
+            .map_err(|_| ParseError::InvalidNumber(self.src[start..end].to_string()))
+    }
+
+    fn parse_flag(&mut self) -> Result<bool, ParseError> {
+        self.skip_whitespace_and_commas();
+        match self.chars.next() {
+            Some((_, '0')) => Ok(false),
+            Some((_, '1')) => Ok(true),
+            Some((pos, c)) => Err(ParseError::UnexpectedCharacter(c, pos)),
+            None => Err(ParseError::UnexpectedEnd),
+        }
+    }
+}
+
+/// Parses an SVG `d=` path-data string and builds the equivalent `PathBuilder`.
+pub fn parse_svg_path(d: &str) -> Result<PathBuilder, ParseError> {
+    let mut p = PathBuilder::new();
+    let mut cursor = Cursor::new(d);
+
+    let mut cur = (0.0f32, 0.0f32);
+    let mut subpath_start = (0.0f32, 0.0f32);
+    // Previous command's second control point, for reflecting S/T smooth curves.
+    let mut prev_cubic_ctrl: Option<(f32, f32)> = None;
+    let mut prev_quad_ctrl: Option<(f32, f32)> = None;
+    let mut command: Option<char> = None;
+    let mut command_pos: usize = 0;
+
+    loop {
+        cursor.skip_whitespace_and_commas();
+        if cursor.peek().is_none() {
+            break;
+        }
+
+        let cmd = if cursor.peek_is_command_start() {
+            let (c, pos) = cursor.next_command().ok_or(ParseError::UnexpectedEnd)?;
+            if !c.is_ascii_alphabetic() {
+                return Err(ParseError::UnexpectedCharacter(c, pos));
+            }
+            command = Some(c);
+            command_pos = pos;
+            c
+        } else {
+            // Repeated argument group: re-use the previous command (implicit
+            // line-to continuation for a move, per the SVG spec). `Z`/`z` takes
+            // no arguments, so it can never start a repeated group; whatever
+            // follows it must be a fresh command.
+            match command {
+                Some('M') => { command = Some('L'); 'L' }
+                Some('m') => { command = Some('l'); 'l' }
+                Some('Z') | Some('z') => {
+                    let (c, pos) = cursor.peek_with_pos().ok_or(ParseError::UnexpectedEnd)?;
+                    return Err(ParseError::UnexpectedCharacter(c, pos));
+                }
+                Some(c) => c,
+                None => return Err(ParseError::MissingCommand),
+            }
+        };
+
+        if cmd != 'C' && cmd != 'c' && cmd != 'S' && cmd != 's' {
+            prev_cubic_ctrl = None;
+        }
+        if cmd != 'Q' && cmd != 'q' && cmd != 'T' && cmd != 't' {
+            prev_quad_ctrl = None;
+        }
+
+        match cmd {
+            'M' | 'm' => {
+                let x = cursor.parse_number()?;
+                let y = cursor.parse_number()?;
+                let (x, y) = if cmd == 'm' { (cur.0 + x, cur.1 + y) } else { (x, y) };
+                p.move_to(x, y);
+                cur = (x, y);
+                subpath_start = cur;
+            }
+            'L' | 'l' => {
+                let x = cursor.parse_number()?;
+                let y = cursor.parse_number()?;
+                let (x, y) = if cmd == 'l' { (cur.0 + x, cur.1 + y) } else { (x, y) };
+                p.line_to(x, y);
+                cur = (x, y);
+            }
+            'H' | 'h' => {
+                let x = cursor.parse_number()?;
+                let x = if cmd == 'h' { cur.0 + x } else { x };
+                p.line_to(x, cur.1);
+                cur = (x, cur.1);
+            }
+            'V' | 'v' => {
+                let y = cursor.parse_number()?;
+                let y = if cmd == 'v' { cur.1 + y } else { y };
+                p.line_to(cur.0, y);
+                cur = (cur.0, y);
+            }
+            'C' | 'c' => {
+                let c1x = cursor.parse_number()?;
+                let c1y = cursor.parse_number()?;
+                let c2x = cursor.parse_number()?;
+                let c2y = cursor.parse_number()?;
+                let x = cursor.parse_number()?;
+                let y = cursor.parse_number()?;
+                let (c1x, c1y, c2x, c2y, x, y) = if cmd == 'c' {
+                    (cur.0 + c1x, cur.1 + c1y, cur.0 + c2x, cur.1 + c2y, cur.0 + x, cur.1 + y)
+                } else {
+                    (c1x, c1y, c2x, c2y, x, y)
+                };
+                p.curve_to(c1x, c1y, c2x, c2y, x, y);
+                prev_cubic_ctrl = Some((c2x, c2y));
+                cur = (x, y);
+            }
+            'S' | 's' => {
+                let c2x = cursor.parse_number()?;
+                let c2y = cursor.parse_number()?;
+                let x = cursor.parse_number()?;
+                let y = cursor.parse_number()?;
+                let (c2x, c2y, x, y) = if cmd == 's' {
+                    (cur.0 + c2x, cur.1 + c2y, cur.0 + x, cur.1 + y)
+                } else {
+                    (c2x, c2y, x, y)
+                };
+                let c1 = match prev_cubic_ctrl {
+                    Some((px, py)) => (2.0 * cur.0 - px, 2.0 * cur.1 - py),
+                    None => cur,
+                };
+                p.curve_to(c1.0, c1.1, c2x, c2y, x, y);
+                prev_cubic_ctrl = Some((c2x, c2y));
+                cur = (x, y);
+            }
+            'Q' | 'q' => {
+                let cx = cursor.parse_number()?;
+                let cy = cursor.parse_number()?;
+                let x = cursor.parse_number()?;
+                let y = cursor.parse_number()?;
+                let (cx, cy, x, y) = if cmd == 'q' { (cur.0 + cx, cur.1 + cy, cur.0 + x, cur.1 + y) } else { (cx, cy, x, y) };
+                p.quad_to(cx, cy, x, y);
+                prev_quad_ctrl = Some((cx, cy));
+                cur = (x, y);
+            }
+            'T' | 't' => {
+                let x = cursor.parse_number()?;
+                let y = cursor.parse_number()?;
+                let (x, y) = if cmd == 't' { (cur.0 + x, cur.1 + y) } else { (x, y) };
+                let c = match prev_quad_ctrl {
+                    Some((px, py)) => (2.0 * cur.0 - px, 2.0 * cur.1 - py),
+                    None => cur,
+                };
+                p.quad_to(c.0, c.1, x, y);
+                prev_quad_ctrl = Some(c);
+                cur = (x, y);
+            }
+            'A' | 'a' => {
+                let rx = cursor.parse_number()?;
+                let ry = cursor.parse_number()?;
+                let x_rot = cursor.parse_number()?;
+                let large_arc = cursor.parse_flag()?;
+                let sweep = cursor.parse_flag()?;
+                let x = cursor.parse_number()?;
+                let y = cursor.parse_number()?;
+                let (x, y) = if cmd == 'a' { (cur.0 + x, cur.1 + y) } else { (x, y) };
+                arc_to_cubics(&mut p, cur, rx, ry, x_rot, large_arc, sweep, (x, y));
+                cur = (x, y);
+            }
+            'Z' | 'z' => {
+                p.close();
+                cur = subpath_start;
+            }
+            other => {
+                return Err(ParseError::UnexpectedCharacter(other, command_pos));
+            }
+        }
+    }
+
+    Ok(p)
+}
+
+// Decomposes an SVG elliptical arc into cubic Bezier segments and routes them
+// through `curve_to`, following the endpoint-to-center parameterization from the
+// SVG spec (F.6.5), split into spans of at most 90 degrees.
+fn arc_to_cubics(p: &mut PathBuilder, from: (f32, f32), rx: f32, ry: f32, x_axis_rotation_deg: f32, large_arc: bool, sweep: bool, to: (f32, f32)) {
+    if (from.0 - to.0).abs() < 1e-9 && (from.1 - to.1).abs() < 1e-9 {
+        return;
+    }
+    let mut rx = rx.abs();
+    let mut ry = ry.abs();
+    if rx < 1e-9 || ry < 1e-9 {
+        p.line_to(to.0, to.1);
+        return;
+    }
+
+    let phi = x_axis_rotation_deg.to_radians();
+    let (sin_phi, cos_phi) = (phi.sin(), phi.cos());
+
+    let dx2 = (from.0 - to.0) / 2.0;
+    let dy2 = (from.1 - to.1) / 2.0;
+    let x1p = cos_phi * dx2 + sin_phi * dy2;
+    let y1p = -sin_phi * dx2 + cos_phi * dy2;
+
+    let lambda = (x1p * x1p) / (rx * rx) + (y1p * y1p) / (ry * ry);
+    if lambda > 1.0 {
+        let scale = lambda.sqrt();
+        rx *= scale;
+        ry *= scale;
+    }
+
+    let sign = if large_arc != sweep { 1.0 } else { -1.0 };
+    let num = (rx * rx * ry * ry - rx * rx * y1p * y1p - ry * ry * x1p * x1p).max(0.0);
+    let den = rx * rx * y1p * y1p + ry * ry * x1p * x1p;
+    let co = if den > 1e-12 { sign * (num / den).sqrt() } else { 0.0 };
+    let cxp = co * (rx * y1p / ry);
+    let cyp = co * -(ry * x1p / rx);
+
+    let cx = cos_phi * cxp - sin_phi * cyp + (from.0 + to.0) / 2.0;
+    let cy = sin_phi * cxp + cos_phi * cyp + (from.1 + to.1) / 2.0;
+
+    let angle = |ux: f32, uy: f32, vx: f32, vy: f32| -> f32 {
+        let dot = ux * vx + uy * vy;
+        let len = ((ux * ux + uy * uy) * (vx * vx + vy * vy)).sqrt();
+        let mut a = (dot / len).clamp(-1.0, 1.0).acos();
+        if ux * vy - uy * vx < 0.0 {
+            a = -a;
+        }
+        a
+    };
+
+    let theta1 = angle(1.0, 0.0, (x1p - cxp) / rx, (y1p - cyp) / ry);
+    let mut delta_theta = angle((x1p - cxp) / rx, (y1p - cyp) / ry, (-x1p - cxp) / rx, (-y1p - cyp) / ry);
+    if !sweep && delta_theta > 0.0 {
+        delta_theta -= 2.0 * std::f32::consts::PI;
+    } else if sweep && delta_theta < 0.0 {
+        delta_theta += 2.0 * std::f32::consts::PI;
+    }
+
+    let num_segments = (delta_theta.abs() / (std::f32::consts::PI / 2.0)).ceil().max(1.0) as i32;
+    let segment_theta = delta_theta / num_segments as f32;
+    let t = (4.0 / 3.0) * (segment_theta / 4.0).tan();
+
+    let mut theta = theta1;
+    let ellipse_point = |theta: f32| -> (f32, f32, f32, f32) {
+        let ct = theta.cos();
+        let st = theta.sin();
+        let ex = cx + rx * ct * cos_phi - ry * st * sin_phi;
+        let ey = cy + rx * ct * sin_phi + ry * st * cos_phi;
+        let dex = -rx * st * cos_phi - ry * ct * sin_phi;
+        let dey = -rx * st * sin_phi + ry * ct * cos_phi;
+        (ex, ey, dex, dey)
+    };
+
+    for _ in 0..num_segments {
+        let (x0, y0, dx0, dy0) = ellipse_point(theta);
+        let theta_next = theta + segment_theta;
+        let (x1, y1, dx1, dy1) = ellipse_point(theta_next);
+
+        let c1 = (x0 + t * dx0, y0 + t * dy0);
+        let c2 = (x1 - t * dx1, y1 - t * dy1);
+        p.curve_to(c1.0, c1.1, c2.0, c2.1, x1, y1);
+
+        theta = theta_next;
+    }
+}